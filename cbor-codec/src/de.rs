@@ -0,0 +1,106 @@
+use smithy4rs_core::schema::prelude::Schema;
+use smithy4rs_core::serde::de::{DeserializeError, Deserializer};
+
+use crate::reader::CborReader;
+
+/// Deserializes Smithy RPC v2 CBOR into `#[derive(SmithyShape)]` types.
+///
+/// Construct with [`CborDeserializer::new`] and pass it to a shape's
+/// generated `ShapeBuilder`, mirroring `JsonDeserializer`.
+pub struct CborDeserializer<'de> {
+    reader: CborReader<'de>,
+}
+
+impl<'de> CborDeserializer<'de> {
+    pub fn new(input: &'de [u8]) -> Self {
+        Self {
+            reader: CborReader::new(input),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for CborDeserializer<'de> {
+    type Error = DeserializeError;
+
+    fn deserialize_boolean(&mut self, _schema: &Schema) -> Result<bool, Self::Error> {
+        self.reader.read_bool()
+    }
+
+    fn deserialize_integer(&mut self, _schema: &Schema) -> Result<i32, Self::Error> {
+        self.reader.read_integer().map(|v| v as i32)
+    }
+
+    fn deserialize_long(&mut self, _schema: &Schema) -> Result<i64, Self::Error> {
+        self.reader.read_integer()
+    }
+
+    fn deserialize_float(&mut self, _schema: &Schema) -> Result<f32, Self::Error> {
+        self.reader.read_float()
+    }
+
+    fn deserialize_double(&mut self, _schema: &Schema) -> Result<f64, Self::Error> {
+        self.reader.read_double()
+    }
+
+    fn deserialize_string(&mut self, _schema: &Schema) -> Result<String, Self::Error> {
+        self.reader.read_text()
+    }
+
+    fn deserialize_blob(&mut self, _schema: &Schema) -> Result<Vec<u8>, Self::Error> {
+        self.reader.read_bytes()
+    }
+
+    fn is_null(&mut self) -> Result<bool, Self::Error> {
+        self.reader.is_null()
+    }
+
+    /// Walks a CBOR map, calling `visit_member` with each member's wire name
+    /// so the caller can route the value into the right `ShapeBuilder`
+    /// field. When `visit_member` returns `false` (unrecognized wire name)
+    /// the value is skipped wholesale via [`CborReader::skip_value`].
+    fn deserialize_struct(
+        &mut self,
+        _schema: &Schema,
+        mut visit_member: impl FnMut(&mut Self, &str) -> Result<bool, Self::Error>,
+    ) -> Result<(), Self::Error> {
+        match self.reader.read_map_header()? {
+            Some(len) => {
+                for _ in 0..len {
+                    let key = self.reader.read_text()?;
+                    if !visit_member(self, &key)? {
+                        self.reader.skip_value()?;
+                    }
+                }
+            }
+            None => {
+                while !self.reader.try_consume_break()? {
+                    let key = self.reader.read_text()?;
+                    if !visit_member(self, &key)? {
+                        self.reader.skip_value()?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn deserialize_list(
+        &mut self,
+        _schema: &Schema,
+        mut visit_element: impl FnMut(&mut Self) -> Result<(), Self::Error>,
+    ) -> Result<(), Self::Error> {
+        match self.reader.read_array_header()? {
+            Some(len) => {
+                for _ in 0..len {
+                    visit_element(self)?;
+                }
+            }
+            None => {
+                while !self.reader.try_consume_break()? {
+                    visit_element(self)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,9 @@
+//! Smithy RPC v2 CBOR codec: [`CborSerializer`]/[`CborDeserializer`].
+
+mod de;
+mod reader;
+mod ser;
+mod writer;
+
+pub use de::CborDeserializer;
+pub use ser::CborSerializer;
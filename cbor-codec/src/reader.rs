@@ -0,0 +1,370 @@
+//! Low-level CBOR item reader.
+//!
+//! Unlike [`CborWriter`](crate::writer::CborWriter), which only ever emits
+//! definite-length items, the reader tolerates both definite- and
+//! indefinite-length arrays, maps, strings and byte strings so that this
+//! codec can consume CBOR produced by other implementations, not just its
+//! own writer.
+
+use smithy4rs_core::serde::de::DeserializeError;
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_TAG: u8 = 6;
+const MAJOR_SIMPLE: u8 = 7;
+
+const SIMPLE_FALSE: u8 = 20;
+const SIMPLE_TRUE: u8 = 21;
+const SIMPLE_NULL: u8 = 22;
+const ADDITIONAL_F16: u8 = 25;
+const ADDITIONAL_F32: u8 = 26;
+const ADDITIONAL_F64: u8 = 27;
+const ADDITIONAL_INDEFINITE: u8 = 31;
+const BREAK: u8 = 0xFF;
+
+/// The decoded length of a CBOR item header: either a known length or
+/// "indefinite", meaning a run of chunks terminated by [`BREAK`].
+pub(crate) enum Length {
+    Definite(u64),
+    Indefinite,
+}
+
+pub(crate) struct CborReader<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> CborReader<'a> {
+    pub(crate) fn new(input: &'a [u8]) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn peek_byte(&self) -> Result<u8, DeserializeError> {
+        self.input
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| DeserializeError::new("unexpected end of CBOR input"))
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DeserializeError> {
+        let end = self.pos + n;
+        let slice = self
+            .input
+            .get(self.pos..end)
+            .ok_or_else(|| DeserializeError::new("unexpected end of CBOR input"))?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    /// Returns `true` and consumes the byte if the next item is the CBOR
+    /// `break` stop-code used to terminate indefinite-length items.
+    pub(crate) fn try_consume_break(&mut self) -> Result<bool, DeserializeError> {
+        if self.peek_byte()? == BREAK {
+            self.pos += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn read_head(&mut self) -> Result<(u8, u8), DeserializeError> {
+        let byte = self.take(1)?[0];
+        Ok((byte >> 5, byte & 0x1F))
+    }
+
+    fn read_length(&mut self, additional: u8) -> Result<Length, DeserializeError> {
+        match additional {
+            0..=23 => Ok(Length::Definite(additional as u64)),
+            24 => Ok(Length::Definite(self.take(1)?[0] as u64)),
+            25 => Ok(Length::Definite(u16::from_be_bytes(
+                self.take(2)?.try_into().unwrap(),
+            ) as u64)),
+            26 => Ok(Length::Definite(u32::from_be_bytes(
+                self.take(4)?.try_into().unwrap(),
+            ) as u64)),
+            27 => Ok(Length::Definite(u64::from_be_bytes(
+                self.take(8)?.try_into().unwrap(),
+            ))),
+            ADDITIONAL_INDEFINITE => Ok(Length::Indefinite),
+            _ => Err(DeserializeError::new("invalid CBOR additional info")),
+        }
+    }
+
+    pub(crate) fn read_integer(&mut self) -> Result<i64, DeserializeError> {
+        let (major, additional) = self.read_head()?;
+        let Length::Definite(len) = self.read_length(additional)? else {
+            return Err(DeserializeError::new("indefinite-length integer"));
+        };
+        match major {
+            MAJOR_UNSIGNED => Ok(len as i64),
+            MAJOR_NEGATIVE => Ok(-1 - len as i64),
+            _ => Err(DeserializeError::new("expected a CBOR integer")),
+        }
+    }
+
+    pub(crate) fn read_float(&mut self) -> Result<f32, DeserializeError> {
+        self.read_double().map(|d| d as f32)
+    }
+
+    pub(crate) fn read_double(&mut self) -> Result<f64, DeserializeError> {
+        let byte = self.take(1)?[0];
+        let additional = byte & 0x1F;
+        match additional {
+            ADDITIONAL_F16 => Err(DeserializeError::new("half-precision floats unsupported")),
+            ADDITIONAL_F32 => Ok(f32::from_be_bytes(self.take(4)?.try_into().unwrap()) as f64),
+            ADDITIONAL_F64 => Ok(f64::from_be_bytes(self.take(8)?.try_into().unwrap())),
+            _ => Err(DeserializeError::new("expected a CBOR float")),
+        }
+    }
+
+    pub(crate) fn read_bool(&mut self) -> Result<bool, DeserializeError> {
+        let byte = self.take(1)?[0];
+        match (byte >> 5, byte & 0x1F) {
+            (MAJOR_SIMPLE, SIMPLE_FALSE) => Ok(false),
+            (MAJOR_SIMPLE, SIMPLE_TRUE) => Ok(true),
+            _ => Err(DeserializeError::new("expected a CBOR boolean")),
+        }
+    }
+
+    pub(crate) fn is_null(&mut self) -> Result<bool, DeserializeError> {
+        if self.peek_byte()? == ((MAJOR_SIMPLE << 5) | SIMPLE_NULL) {
+            self.pos += 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Reads a (possibly chunked, indefinite-length) text string, owning the
+    /// result since chunks may need to be concatenated.
+    pub(crate) fn read_text(&mut self) -> Result<String, DeserializeError> {
+        let (major, additional) = self.read_head()?;
+        if major != MAJOR_TEXT {
+            return Err(DeserializeError::new("expected a CBOR text string"));
+        }
+        match self.read_length(additional)? {
+            Length::Definite(len) => {
+                let bytes = self.take(len as usize)?;
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|_| DeserializeError::new("invalid UTF-8 in CBOR text string"))
+            }
+            Length::Indefinite => {
+                let mut out = String::new();
+                while !self.try_consume_break()? {
+                    out.push_str(&self.read_text()?);
+                }
+                Ok(out)
+            }
+        }
+    }
+
+    pub(crate) fn read_bytes(&mut self) -> Result<Vec<u8>, DeserializeError> {
+        let (major, additional) = self.read_head()?;
+        if major != MAJOR_BYTES {
+            return Err(DeserializeError::new("expected a CBOR byte string"));
+        }
+        match self.read_length(additional)? {
+            Length::Definite(len) => Ok(self.take(len as usize)?.to_vec()),
+            Length::Indefinite => {
+                let mut out = Vec::new();
+                while !self.try_consume_break()? {
+                    out.extend(self.read_bytes()?);
+                }
+                Ok(out)
+            }
+        }
+    }
+
+    /// Reads an array header, returning its length (or `None` for
+    /// indefinite-length arrays, which the caller drains until `break`).
+    pub(crate) fn read_array_header(&mut self) -> Result<Option<u64>, DeserializeError> {
+        let (major, additional) = self.read_head()?;
+        if major != MAJOR_ARRAY {
+            return Err(DeserializeError::new("expected a CBOR array"));
+        }
+        match self.read_length(additional)? {
+            Length::Definite(len) => Ok(Some(len)),
+            Length::Indefinite => Ok(None),
+        }
+    }
+
+    /// Reads a map header, returning its entry count (or `None` for
+    /// indefinite-length maps, which the caller drains until `break`).
+    pub(crate) fn read_map_header(&mut self) -> Result<Option<u64>, DeserializeError> {
+        let (major, additional) = self.read_head()?;
+        if major != MAJOR_MAP {
+            return Err(DeserializeError::new("expected a CBOR map"));
+        }
+        match self.read_length(additional)? {
+            Length::Definite(len) => Ok(Some(len)),
+            Length::Indefinite => Ok(None),
+        }
+    }
+
+    /// Skips over a single CBOR item of any type, used to discard unknown
+    /// structure map keys.
+    pub(crate) fn skip_value(&mut self) -> Result<(), DeserializeError> {
+        let start = self.pos;
+        let (major, additional) = self.read_head()?;
+        self.pos = start;
+        match major {
+            MAJOR_UNSIGNED | MAJOR_NEGATIVE => {
+                self.read_integer()?;
+            }
+            MAJOR_BYTES => {
+                self.read_bytes()?;
+            }
+            MAJOR_TEXT => {
+                self.read_text()?;
+            }
+            MAJOR_ARRAY => {
+                self.pos += 1;
+                match self.read_length(additional)? {
+                    Length::Definite(len) => {
+                        for _ in 0..len {
+                            self.skip_value()?;
+                        }
+                    }
+                    Length::Indefinite => {
+                        while !self.try_consume_break()? {
+                            self.skip_value()?;
+                        }
+                    }
+                }
+            }
+            MAJOR_MAP => {
+                self.pos += 1;
+                match self.read_length(additional)? {
+                    Length::Definite(len) => {
+                        for _ in 0..len {
+                            self.skip_value()?;
+                            self.skip_value()?;
+                        }
+                    }
+                    Length::Indefinite => {
+                        while !self.try_consume_break()? {
+                            self.skip_value()?;
+                            self.skip_value()?;
+                        }
+                    }
+                }
+            }
+            MAJOR_TAG => {
+                self.pos += 1;
+                self.read_length(additional)?;
+                self.skip_value()?;
+            }
+            MAJOR_SIMPLE => {
+                self.pos += 1;
+                match additional {
+                    ADDITIONAL_F16 => {
+                        self.take(2)?;
+                    }
+                    ADDITIONAL_F32 => {
+                        self.take(4)?;
+                    }
+                    ADDITIONAL_F64 => {
+                        self.take(8)?;
+                    }
+                    24 => {
+                        self.take(1)?;
+                    }
+                    _ => {}
+                }
+            }
+            _ => return Err(DeserializeError::new("invalid CBOR major type")),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::writer::CborWriter;
+
+    use super::*;
+
+    #[test]
+    fn round_trips_booleans() {
+        let mut buf = Vec::new();
+        CborWriter::new(&mut buf).write_bool(true);
+        CborWriter::new(&mut buf).write_bool(false);
+        let mut reader = CborReader::new(&buf);
+        assert!(reader.read_bool().unwrap());
+        assert!(!reader.read_bool().unwrap());
+    }
+
+    #[test]
+    fn rejects_an_integer_as_a_boolean() {
+        // CBOR `20` (0x14: major 0, additional 20) shares its low 5 bits
+        // with the `false` simple value (major 7, additional 20).
+        let mut buf = Vec::new();
+        CborWriter::new(&mut buf).write_unsigned(20);
+        let mut reader = CborReader::new(&buf);
+        assert!(reader.read_bool().is_err());
+    }
+
+    #[test]
+    fn round_trips_negative_integer_boundary() {
+        let mut buf = Vec::new();
+        CborWriter::new(&mut buf).write_integer(i64::MIN);
+        let mut reader = CborReader::new(&buf);
+        assert_eq!(reader.read_integer().unwrap(), i64::MIN);
+    }
+
+    #[test]
+    fn round_trips_float_widths() {
+        let mut buf = Vec::new();
+        CborWriter::new(&mut buf).write_float(1.5);
+        CborWriter::new(&mut buf).write_double(2.5);
+        let mut reader = CborReader::new(&buf);
+        assert_eq!(reader.read_float().unwrap(), 1.5);
+        assert_eq!(reader.read_double().unwrap(), 2.5);
+    }
+
+    #[test]
+    fn round_trips_empty_map() {
+        let mut buf = Vec::new();
+        CborWriter::new(&mut buf).write_map_header(0);
+        let mut reader = CborReader::new(&buf);
+        assert_eq!(reader.read_map_header().unwrap(), Some(0));
+    }
+
+    #[test]
+    fn reads_indefinite_length_array() {
+        // [_ 1, 2, 3]
+        let buf = vec![0x9F, 0x01, 0x02, 0x03, 0xFF];
+        let mut reader = CborReader::new(&buf);
+        assert_eq!(reader.read_array_header().unwrap(), None);
+        let mut values = Vec::new();
+        while !reader.try_consume_break().unwrap() {
+            values.push(reader.read_integer().unwrap());
+        }
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn skip_value_discards_an_unknown_map_entry() {
+        let mut buf = Vec::new();
+        let mut writer = CborWriter::new(&mut buf);
+        writer.write_map_header(2);
+        writer.write_text("known");
+        writer.write_integer(1);
+        writer.write_text("unknown");
+        writer.write_array_header(2);
+        writer.write_integer(2);
+        writer.write_integer(3);
+
+        let mut reader = CborReader::new(&buf);
+        assert_eq!(reader.read_map_header().unwrap(), Some(2));
+        assert_eq!(reader.read_text().unwrap(), "known");
+        assert_eq!(reader.read_integer().unwrap(), 1);
+        assert_eq!(reader.read_text().unwrap(), "unknown");
+        reader.skip_value().unwrap();
+        assert!(reader.peek_byte().is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use smithy4rs_core::schema::prelude::Schema;
+use smithy4rs_core::serde::serializers::{ListSerializer, SerializeError, Serializer, StructSerializer};
+
+use crate::writer::CborWriter;
+
+/// Serializes `#[derive(SmithyShape)]` types to Smithy RPC v2 CBOR.
+///
+/// Mirrors `JsonSerializer`: construct with [`CborSerializer::new`] and pass
+/// it to a shape's generated `serialize_with_schema`.
+pub struct CborSerializer<'a> {
+    writer: CborWriter<'a>,
+}
+
+impl<'a> CborSerializer<'a> {
+    pub fn new(buf: &'a mut Vec<u8>) -> Self {
+        Self {
+            writer: CborWriter::new(buf),
+        }
+    }
+}
+
+impl<'a> Serializer for CborSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+    type SerializeStruct = CborStructSerializer<'a>;
+    type SerializeList = CborListSerializer<'a>;
+
+    fn serialize_struct(
+        self,
+        schema: &Schema,
+        size: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let _ = schema;
+        let mut writer = self.writer;
+        writer.write_map_header(size);
+        Ok(CborStructSerializer { writer })
+    }
+
+    fn serialize_list(
+        self,
+        schema: &Schema,
+        size: usize,
+    ) -> Result<Self::SerializeList, Self::Error> {
+        let _ = schema;
+        let mut writer = self.writer;
+        writer.write_array_header(size);
+        Ok(CborListSerializer { writer })
+    }
+
+    fn serialize_boolean(mut self, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_bool(value);
+        Ok(())
+    }
+
+    fn serialize_integer(mut self, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_integer(value as i64);
+        Ok(())
+    }
+
+    fn serialize_long(mut self, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_integer(value);
+        Ok(())
+    }
+
+    fn serialize_float(mut self, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_float(value);
+        Ok(())
+    }
+
+    fn serialize_double(mut self, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_double(value);
+        Ok(())
+    }
+
+    fn serialize_string(mut self, value: &str) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_text(value);
+        Ok(())
+    }
+
+    fn serialize_blob(mut self, value: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_bytes(value);
+        Ok(())
+    }
+
+    fn serialize_null(mut self) -> Result<Self::Ok, Self::Error> {
+        self.writer.write_null();
+        Ok(())
+    }
+}
+
+/// Writes a structure's members as CBOR map entries keyed by wire name.
+/// Every member it is given is written; absent optional members are left
+/// out by the caller, which also counts only present members in the map
+/// header passed to `serialize_struct`.
+pub struct CborStructSerializer<'a> {
+    writer: CborWriter<'a>,
+}
+
+impl<'a> StructSerializer for CborStructSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_member<T: ?Sized + smithy4rs_core::serde::serializers::SerializeWithSchema>(
+        &mut self,
+        wire_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.writer.write_text(wire_name);
+        value.serialize_with_schema(member_schema, CborSerializer { writer: CborWriter::new(self.writer.buf_mut()) })?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+/// Writes a list's elements as a CBOR array, reusing the element schema for
+/// every entry the way the JSON codec does.
+pub struct CborListSerializer<'a> {
+    writer: CborWriter<'a>,
+}
+
+impl<'a> ListSerializer for CborListSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + smithy4rs_core::serde::serializers::SerializeWithSchema>(
+        &mut self,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        value.serialize_with_schema(member_schema, CborSerializer { writer: CborWriter::new(self.writer.buf_mut()) })?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smithy4rs_core::schema::prelude::{INTEGER, STRING};
+    use smithy4rs_core::serde::de::{DeserializeWithSchema, Deserializer};
+    use smithy4rs_core::serde::serializers::SerializeWithSchema;
+    use smithy4rs_core::smithy;
+
+    use super::*;
+    use crate::CborDeserializer;
+
+    smithy!("com.test#Widget": {
+        structure WIDGET_SCHEMA {
+            NAME: STRING = "name"
+            COUNT: INTEGER = "count"
+        }
+    });
+
+    struct Widget {
+        name: String,
+        count: Option<i32>,
+    }
+
+    impl SerializeWithSchema for Widget {
+        fn serialize_with_schema<S: Serializer>(
+            &self,
+            schema: &Schema,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            let present = 1 + if self.count.is_some() { 1 } else { 0 };
+            let mut struct_ser = serializer.serialize_struct(schema, present)?;
+            struct_ser.serialize_member(NAME.wire_name(), &NAME, &self.name)?;
+            if let Some(count) = &self.count {
+                struct_ser.serialize_member(COUNT.wire_name(), &COUNT, count)?;
+            }
+            struct_ser.end()
+        }
+    }
+
+    fn deserialize_widget(schema: &Schema, de: &mut CborDeserializer) -> (String, Option<i32>) {
+        let mut name = None;
+        let mut count = None;
+        de.deserialize_struct(schema, |de, key| {
+            if key == NAME.wire_name() {
+                name = Some(String::deserialize_with_schema(&NAME, de)?);
+                return Ok(true);
+            }
+            if key == COUNT.wire_name() {
+                count = Some(i32::deserialize_with_schema(&COUNT, de)?);
+                return Ok(true);
+            }
+            Ok(false)
+        })
+        .unwrap();
+        (name.unwrap(), count)
+    }
+
+    /// Regression test for a map header whose declared size didn't match the
+    /// number of members actually written: `serialize_struct` must be driven
+    /// with the count of *present* members so an absent optional doesn't
+    /// desync the rest of the CBOR buffer.
+    #[test]
+    fn round_trips_a_struct_with_an_absent_optional_member() {
+        let widget = Widget {
+            name: "widget-1".to_string(),
+            count: None,
+        };
+        let mut buf = Vec::new();
+        widget
+            .serialize_with_schema(&WIDGET_SCHEMA, CborSerializer::new(&mut buf))
+            .unwrap();
+
+        let mut de = CborDeserializer::new(&buf);
+        let (name, count) = deserialize_widget(&WIDGET_SCHEMA, &mut de);
+        assert_eq!(name, "widget-1");
+        assert_eq!(count, None);
+    }
+
+    #[test]
+    fn round_trips_a_struct_with_a_present_optional_member() {
+        let widget = Widget {
+            name: "widget-2".to_string(),
+            count: Some(7),
+        };
+        let mut buf = Vec::new();
+        widget
+            .serialize_with_schema(&WIDGET_SCHEMA, CborSerializer::new(&mut buf))
+            .unwrap();
+
+        let mut de = CborDeserializer::new(&buf);
+        let (name, count) = deserialize_widget(&WIDGET_SCHEMA, &mut de);
+        assert_eq!(name, "widget-2");
+        assert_eq!(count, Some(7));
+    }
+}
@@ -0,0 +1,111 @@
+//! Low-level, definite-length-only CBOR item writer.
+//!
+//! This is intentionally minimal: it knows nothing about Smithy schemas, it
+//! just encodes CBOR major types onto an in-memory buffer. [`CborSerializer`]
+//! (see `ser.rs`) drives it using the schema as the source of truth for
+//! member ordering and wire names.
+//!
+//! [`CborSerializer`]: crate::CborSerializer
+
+const MAJOR_UNSIGNED: u8 = 0;
+const MAJOR_NEGATIVE: u8 = 1;
+const MAJOR_BYTES: u8 = 2;
+const MAJOR_TEXT: u8 = 3;
+const MAJOR_ARRAY: u8 = 4;
+const MAJOR_MAP: u8 = 5;
+const MAJOR_SIMPLE: u8 = 7;
+
+const SIMPLE_FALSE: u8 = 20;
+const SIMPLE_TRUE: u8 = 21;
+const SIMPLE_NULL: u8 = 22;
+const ADDITIONAL_F32: u8 = 26;
+const ADDITIONAL_F64: u8 = 27;
+
+/// Appends definite-length CBOR items to a borrowed byte buffer.
+pub(crate) struct CborWriter<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl<'a> CborWriter<'a> {
+    pub(crate) fn new(buf: &'a mut Vec<u8>) -> Self {
+        Self { buf }
+    }
+
+    /// Reborrows the underlying buffer so a nested serializer can be built
+    /// for a member/element value without taking ownership of this writer.
+    pub(crate) fn buf_mut(&mut self) -> &mut Vec<u8> {
+        self.buf
+    }
+
+    fn write_head(&mut self, major: u8, len: u64) {
+        let major = major << 5;
+        match len {
+            0..=23 => self.buf.push(major | len as u8),
+            24..=0xFF => {
+                self.buf.push(major | 24);
+                self.buf.push(len as u8);
+            }
+            0x100..=0xFFFF => {
+                self.buf.push(major | 25);
+                self.buf.extend_from_slice(&(len as u16).to_be_bytes());
+            }
+            0x1_0000..=0xFFFF_FFFF => {
+                self.buf.push(major | 26);
+                self.buf.extend_from_slice(&(len as u32).to_be_bytes());
+            }
+            _ => {
+                self.buf.push(major | 27);
+                self.buf.extend_from_slice(&len.to_be_bytes());
+            }
+        }
+    }
+
+    pub(crate) fn write_unsigned(&mut self, value: u64) {
+        self.write_head(MAJOR_UNSIGNED, value);
+    }
+
+    pub(crate) fn write_integer(&mut self, value: i64) {
+        if value >= 0 {
+            self.write_unsigned(value as u64);
+        } else {
+            self.write_head(MAJOR_NEGATIVE, (-1 - value) as u64);
+        }
+    }
+
+    pub(crate) fn write_float(&mut self, value: f32) {
+        self.buf.push((MAJOR_SIMPLE << 5) | ADDITIONAL_F32);
+        self.buf.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub(crate) fn write_double(&mut self, value: f64) {
+        self.buf.push((MAJOR_SIMPLE << 5) | ADDITIONAL_F64);
+        self.buf.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub(crate) fn write_bool(&mut self, value: bool) {
+        self.buf
+            .push((MAJOR_SIMPLE << 5) | if value { SIMPLE_TRUE } else { SIMPLE_FALSE });
+    }
+
+    pub(crate) fn write_null(&mut self) {
+        self.buf.push((MAJOR_SIMPLE << 5) | SIMPLE_NULL);
+    }
+
+    pub(crate) fn write_text(&mut self, value: &str) {
+        self.write_head(MAJOR_TEXT, value.len() as u64);
+        self.buf.extend_from_slice(value.as_bytes());
+    }
+
+    pub(crate) fn write_bytes(&mut self, value: &[u8]) {
+        self.write_head(MAJOR_BYTES, value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    pub(crate) fn write_array_header(&mut self, len: usize) {
+        self.write_head(MAJOR_ARRAY, len as u64);
+    }
+
+    pub(crate) fn write_map_header(&mut self, len: usize) {
+        self.write_head(MAJOR_MAP, len as u64);
+    }
+}
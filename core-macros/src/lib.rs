@@ -0,0 +1,172 @@
+//! `#[derive(SmithyShape)]`: generates a shape's `SerializeWithSchema` impl
+//! and its `{Shape}Builder` (which implements `ShapeBuilder` and
+//! `DeserializeWithSchema`) from a struct whose members are annotated with
+//! `#[smithy_schema(MEMBER_CONST)]`, pointing at consts defined by the
+//! `smithy!` macro.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, PathArguments, Type};
+
+#[proc_macro_derive(SmithyShape, attributes(smithy_schema))]
+pub fn derive_smithy_shape(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_name = &input.ident;
+    let builder_name = format_ident!("{struct_name}Builder");
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => panic!("SmithyShape only supports structs with named fields"),
+        },
+        _ => panic!("SmithyShape only supports structs"),
+    };
+
+    let mut present_exprs = Vec::new();
+    let mut serialize_stmts = Vec::new();
+    let mut builder_fields = Vec::new();
+    let mut build_fields = Vec::new();
+    let mut deserialize_arms = Vec::new();
+
+    for field in fields {
+        let field_name = field.ident.as_ref().expect("named field");
+        let member_const = schema_attr(&field.attrs).unwrap_or_else(|| {
+            panic!("field `{field_name}` must have #[smithy_schema(MEMBER_CONST)]")
+        });
+        let ty = &field.ty;
+
+        if let Some(inner) = option_inner(ty) {
+            present_exprs.push(quote! { if self.#field_name.is_some() { 1 } else { 0 } });
+            serialize_stmts.push(quote! {
+                if let Some(value) = &self.#field_name {
+                    struct_ser.serialize_member(#member_const.wire_name(), &#member_const, value)?;
+                }
+            });
+            builder_fields.push(quote! { #field_name: Option<#inner> });
+            build_fields.push(quote! { #field_name: self.#field_name });
+            deserialize_arms.push(quote! {
+                if key == #member_const.wire_name() {
+                    builder.#field_name = Some(
+                        <#inner as smithy4rs_core::serde::de::DeserializeWithSchema<'de>>::deserialize_with_schema(
+                            &#member_const, de,
+                        )?,
+                    );
+                    return Ok(true);
+                }
+            });
+        } else {
+            present_exprs.push(quote! { 1 });
+            serialize_stmts.push(quote! {
+                struct_ser.serialize_member(#member_const.wire_name(), &#member_const, &self.#field_name)?;
+            });
+            builder_fields.push(quote! { #field_name: Option<#ty> });
+            build_fields.push(quote! {
+                #field_name: self.#field_name.ok_or_else(|| {
+                    smithy4rs_core::serde::BuildError::missing_member(#member_const.wire_name())
+                })?
+            });
+            deserialize_arms.push(quote! {
+                if key == #member_const.wire_name() {
+                    builder.#field_name = Some(
+                        <#ty as smithy4rs_core::serde::de::DeserializeWithSchema<'de>>::deserialize_with_schema(
+                            &#member_const, de,
+                        )?,
+                    );
+                    return Ok(true);
+                }
+            });
+        }
+    }
+
+    let present_sum = if present_exprs.is_empty() {
+        quote! { 0 }
+    } else {
+        quote! { #(#present_exprs)+* }
+    };
+
+    let expanded = quote! {
+        impl smithy4rs_core::serde::serializers::SerializeWithSchema for #struct_name {
+            fn serialize_with_schema<S: smithy4rs_core::serde::serializers::Serializer>(
+                &self,
+                schema: &smithy4rs_core::schema::Schema,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                use smithy4rs_core::serde::serializers::StructSerializer;
+
+                let present: usize = #present_sum;
+                let mut struct_ser = serializer.serialize_struct(schema, present)?;
+                #(#serialize_stmts)*
+                struct_ser.end()
+            }
+        }
+
+        #[derive(Default)]
+        pub struct #builder_name {
+            #(#builder_fields,)*
+        }
+
+        impl smithy4rs_core::serde::ShapeBuilder for #builder_name {
+            type Shape = #struct_name;
+
+            fn build(self) -> Result<#struct_name, smithy4rs_core::serde::BuildError> {
+                Ok(#struct_name {
+                    #(#build_fields,)*
+                })
+            }
+        }
+
+        impl<'de> smithy4rs_core::serde::de::DeserializeWithSchema<'de> for #builder_name {
+            fn deserialize_with_schema<D: smithy4rs_core::serde::de::Deserializer<'de>>(
+                schema: &smithy4rs_core::schema::Schema,
+                deserializer: &mut D,
+            ) -> Result<Self, D::Error> {
+                let mut builder = Self::default();
+                deserializer.deserialize_struct(schema, |de, key| {
+                    #(#deserialize_arms)*
+                    Ok(false)
+                })?;
+                Ok(builder)
+            }
+        }
+
+        impl<'de> smithy4rs_core::serde::de::DeserializeWithSchema<'de> for #struct_name {
+            fn deserialize_with_schema<D: smithy4rs_core::serde::de::Deserializer<'de>>(
+                schema: &smithy4rs_core::schema::Schema,
+                deserializer: &mut D,
+            ) -> Result<Self, D::Error> {
+                let builder = <#builder_name as smithy4rs_core::serde::de::DeserializeWithSchema<'de>>::deserialize_with_schema(
+                    schema, deserializer,
+                )?;
+                Ok(<#builder_name as smithy4rs_core::serde::ShapeBuilder>::build(builder)?)
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// Extracts `IDENT` from a field or struct's `#[smithy_schema(IDENT)]`.
+fn schema_attr(attrs: &[syn::Attribute]) -> Option<syn::Path> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("smithy_schema"))
+        .and_then(|attr| attr.parse_args::<syn::Path>().ok())
+}
+
+/// Returns `Some(T)` if `ty` is `Option<T>`, else `None`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,18 @@
+//! Schema-driven Smithy shape (de)serialization, shared by every wire
+//! format codec: [`schema`] describes a shape's wire representation,
+//! [`serde`] defines the traits codecs implement to drive it, and
+//! [`derive::SmithyShape`] generates a shape's `SerializeWithSchema` impl
+//! and builder from a struct annotated with `#[smithy_schema(...)]`.
+
+pub mod schema;
+pub mod serde;
+
+mod smithy_macro;
+
+pub use indexmap::IndexMap;
+
+/// Re-exports the `#[derive(SmithyShape)]` macro under `derive::`, mirroring
+/// how callers import it: `smithy4rs_core::derive::SmithyShape`.
+pub mod derive {
+    pub use smithy4rs_core_macros::SmithyShape;
+}
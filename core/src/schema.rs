@@ -0,0 +1,196 @@
+//! Runtime shape metadata. A [`Schema`] describes how one value (a scalar,
+//! list, map, or structure) is represented on the wire, so codecs can drive
+//! serialization/deserialization generically instead of hard-coding each
+//! shape. Schemas are either baked in at compile time by the [`crate::smithy`]
+//! macro or assembled at runtime by [`SchemaBuilder`] (see `infer_schema` in
+//! the JSON codec).
+
+/// The Smithy shape kinds a [`Schema`] can describe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShapeType {
+    Boolean,
+    Integer,
+    Long,
+    Float,
+    Double,
+    String,
+    Blob,
+    Document,
+    List,
+    Map,
+    Structure,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[doc(hidden)]
+pub struct SchemaData {
+    pub shape_type: ShapeType,
+    pub name: &'static str,
+    pub wire_name: &'static str,
+    pub optional: bool,
+    pub members: &'static [Schema],
+    pub element: Option<&'static Schema>,
+    pub map_key: Option<&'static Schema>,
+    pub map_value: Option<&'static Schema>,
+}
+
+/// A shape's schema: its [`ShapeType`] plus whatever the shape kind needs
+/// (a structure's members, a list's element type, a map's key/value types).
+/// Cheap to copy — it's a reference to schema data that is either a `static`
+/// baked in by [`crate::smithy`] or leaked once by [`SchemaBuilder::build`].
+#[derive(Clone, Copy, Debug)]
+pub struct Schema(#[doc(hidden)] pub &'static SchemaData);
+
+impl Schema {
+    pub fn shape_type(&self) -> ShapeType {
+        self.0.shape_type
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.0.name
+    }
+
+    /// The wire name this schema is keyed under in its parent structure or
+    /// map. Empty for a schema that isn't a member (e.g. a prelude scalar).
+    pub fn wire_name(&self) -> &'static str {
+        self.0.wire_name
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.0.optional
+    }
+
+    pub fn members(&self) -> &'static [Schema] {
+        self.0.members
+    }
+
+    pub fn element(&self) -> Option<&'static Schema> {
+        self.0.element
+    }
+
+    pub fn map_key(&self) -> Option<&'static Schema> {
+        self.0.map_key
+    }
+
+    pub fn map_value(&self) -> Option<&'static Schema> {
+        self.0.map_value
+    }
+}
+
+macro_rules! prelude_scalar {
+    ($name:ident, $shape_type:expr) => {
+        pub const $name: Schema = Schema(&SchemaData {
+            shape_type: $shape_type,
+            name: "",
+            wire_name: "",
+            optional: false,
+            members: &[],
+            element: None,
+            map_key: None,
+            map_value: None,
+        });
+    };
+}
+
+prelude_scalar!(BOOLEAN, ShapeType::Boolean);
+prelude_scalar!(INTEGER, ShapeType::Integer);
+prelude_scalar!(LONG, ShapeType::Long);
+prelude_scalar!(FLOAT, ShapeType::Float);
+prelude_scalar!(DOUBLE, ShapeType::Double);
+prelude_scalar!(STRING, ShapeType::String);
+prelude_scalar!(BLOB, ShapeType::Blob);
+prelude_scalar!(DOCUMENT, ShapeType::Document);
+
+/// Assembles a [`Schema`] at runtime (e.g. from [`infer_schema`][ifs]),
+/// as an alternative to defining one at compile time with [`crate::smithy`].
+/// `build()` leaks the schema's owned strings/slices to get the `'static`
+/// data a [`Schema`] needs — acceptable because inferred schemas are
+/// long-lived singletons, not created in a hot loop.
+///
+/// [ifs]: https://docs.rs/smithy4rs-json-codec (`infer_schema`)
+pub struct SchemaBuilder {
+    shape_type: ShapeType,
+    name: String,
+    element: Option<Schema>,
+    members: Vec<(String, Schema, bool)>,
+}
+
+impl SchemaBuilder {
+    pub fn scalar(shape_type: ShapeType) -> Self {
+        Self {
+            shape_type,
+            name: String::new(),
+            element: None,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn list(name: impl Into<String>) -> Self {
+        Self {
+            shape_type: ShapeType::List,
+            name: name.into(),
+            element: None,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn structure(name: impl Into<String>) -> Self {
+        Self {
+            shape_type: ShapeType::Structure,
+            name: name.into(),
+            element: None,
+            members: Vec::new(),
+        }
+    }
+
+    /// Sets a list's element type. Only meaningful on a builder started
+    /// with [`SchemaBuilder::list`].
+    pub fn element(mut self, schema: Schema) -> Self {
+        self.element = Some(schema);
+        self
+    }
+
+    /// Adds a structure member. Only meaningful on a builder started with
+    /// [`SchemaBuilder::structure`].
+    pub fn member(mut self, wire_name: impl Into<String>, schema: Schema, optional: bool) -> Self {
+        self.members.push((wire_name.into(), schema, optional));
+        self
+    }
+
+    pub fn build(self) -> Schema {
+        let name: &'static str = Box::leak(self.name.into_boxed_str());
+        let element: Option<&'static Schema> =
+            self.element.map(|schema| &*Box::leak(Box::new(schema)));
+        let members: &'static [Schema] = Box::leak(
+            self.members
+                .into_iter()
+                .map(|(wire_name, schema, optional)| {
+                    let wire_name: &'static str = Box::leak(wire_name.into_boxed_str());
+                    Schema(Box::leak(Box::new(SchemaData {
+                        wire_name,
+                        optional,
+                        ..*schema.0
+                    })))
+                })
+                .collect::<Vec<_>>()
+                .into_boxed_slice(),
+        );
+        Schema(Box::leak(Box::new(SchemaData {
+            shape_type: self.shape_type,
+            name,
+            wire_name: "",
+            optional: false,
+            members,
+            element,
+            map_key: None,
+            map_value: None,
+        })))
+    }
+}
+
+pub mod prelude {
+    pub use super::{
+        Schema, SchemaBuilder, ShapeType, BLOB, BOOLEAN, DOCUMENT, DOUBLE, FLOAT, INTEGER, LONG,
+        STRING,
+    };
+}
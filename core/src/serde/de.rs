@@ -0,0 +1,142 @@
+//! Schema-driven deserialization: [`DeserializeWithSchema`] is implemented
+//! by every shape and primitive (and by each shape's generated builder);
+//! [`Deserializer`] is implemented by each codec and driven by the former.
+
+use std::fmt;
+
+use crate::schema::Schema;
+use crate::serde::BuildError;
+use crate::IndexMap;
+
+#[derive(Debug)]
+pub struct DeserializeError(String);
+
+impl DeserializeError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl From<BuildError> for DeserializeError {
+    fn from(error: BuildError) -> Self {
+        DeserializeError::new(error.to_string())
+    }
+}
+
+/// Implemented once per wire format. `'de` is the lifetime of the buffer
+/// being read, so borrowing codecs (e.g. `JsonDeserializer`) can hand back
+/// zero-copy slices of it.
+pub trait Deserializer<'de> {
+    type Error: std::error::Error + From<BuildError>;
+
+    fn deserialize_boolean(&mut self, schema: &Schema) -> Result<bool, Self::Error>;
+    fn deserialize_integer(&mut self, schema: &Schema) -> Result<i32, Self::Error>;
+    fn deserialize_long(&mut self, schema: &Schema) -> Result<i64, Self::Error>;
+    fn deserialize_float(&mut self, schema: &Schema) -> Result<f32, Self::Error>;
+    fn deserialize_double(&mut self, schema: &Schema) -> Result<f64, Self::Error>;
+    fn deserialize_string(&mut self, schema: &Schema) -> Result<String, Self::Error>;
+    fn deserialize_blob(&mut self, schema: &Schema) -> Result<Vec<u8>, Self::Error>;
+    fn is_null(&mut self) -> Result<bool, Self::Error>;
+
+    /// Walks a struct's (or map's) members, calling `visit_member` with each
+    /// member's wire name. `visit_member` returns whether it consumed the
+    /// value; when it didn't (e.g. an unrecognized wire name), the
+    /// implementation is responsible for skipping the value wholesale.
+    fn deserialize_struct(
+        &mut self,
+        schema: &Schema,
+        visit_member: impl FnMut(&mut Self, &str) -> Result<bool, Self::Error>,
+    ) -> Result<(), Self::Error>
+    where
+        Self: Sized;
+
+    fn deserialize_list(
+        &mut self,
+        schema: &Schema,
+        visit_element: impl FnMut(&mut Self) -> Result<(), Self::Error>,
+    ) -> Result<(), Self::Error>
+    where
+        Self: Sized;
+}
+
+/// Implemented by every deserializable Smithy shape and primitive, and by
+/// each shape's generated `{Shape}Builder`.
+pub trait DeserializeWithSchema<'de>: Sized {
+    fn deserialize_with_schema<D: Deserializer<'de>>(
+        schema: &Schema,
+        deserializer: &mut D,
+    ) -> Result<Self, D::Error>;
+}
+
+impl<'de> DeserializeWithSchema<'de> for bool {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.deserialize_boolean(schema)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for i32 {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.deserialize_integer(schema)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for i64 {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.deserialize_long(schema)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for f32 {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.deserialize_float(schema)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for f64 {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.deserialize_double(schema)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for String {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        deserializer.deserialize_string(schema)
+    }
+}
+
+impl<'de, T: DeserializeWithSchema<'de>> DeserializeWithSchema<'de> for Vec<T> {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        let element_schema = schema
+            .element()
+            .expect("list schema must declare an element type");
+        let mut items = Vec::new();
+        deserializer.deserialize_list(schema, |de| {
+            items.push(T::deserialize_with_schema(element_schema, de)?);
+            Ok(())
+        })?;
+        Ok(items)
+    }
+}
+
+impl<'de> DeserializeWithSchema<'de> for IndexMap<String, String> {
+    fn deserialize_with_schema<D: Deserializer<'de>>(schema: &Schema, deserializer: &mut D) -> Result<Self, D::Error> {
+        let value_schema = schema
+            .map_value()
+            .expect("map schema must declare a value type");
+        let mut map = IndexMap::new();
+        deserializer.deserialize_struct(schema, |de, key| {
+            let value = String::deserialize_with_schema(value_schema, de)?;
+            map.insert(key.to_string(), value);
+            Ok(true)
+        })?;
+        Ok(map)
+    }
+}
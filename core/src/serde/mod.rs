@@ -0,0 +1,34 @@
+//! Schema-driven (de)serialization, split into the `de`/`serializers`
+//! submodules the way the wire formats that implement them are split into
+//! a deserializer and a serializer.
+
+pub mod de;
+pub mod serializers;
+
+use std::fmt;
+
+/// Returned by a generated `{Shape}Builder::build()` when a required
+/// member was never set.
+#[derive(Debug)]
+pub struct BuildError(String);
+
+impl BuildError {
+    pub fn missing_member(wire_name: &str) -> Self {
+        Self(format!("missing required member `{wire_name}`"))
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Implemented by every shape's generated `{Shape}Builder`.
+pub trait ShapeBuilder: Sized {
+    type Shape;
+
+    fn build(self) -> Result<Self::Shape, BuildError>;
+}
@@ -0,0 +1,165 @@
+//! Schema-driven serialization: [`SerializeWithSchema`] is implemented by
+//! every shape and primitive; [`Serializer`] is implemented by each codec
+//! (`JsonSerializer`, `CborSerializer`, ...) and driven by the former.
+
+use std::fmt;
+
+use crate::schema::Schema;
+use crate::IndexMap;
+
+#[derive(Debug)]
+pub struct SerializeError(String);
+
+impl SerializeError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+/// Implemented by every serializable Smithy shape and primitive. `schema`
+/// carries the wire-relevant type info (e.g. a list's element schema) that
+/// the value itself doesn't know about.
+pub trait SerializeWithSchema {
+    fn serialize_with_schema<S: Serializer>(
+        &self,
+        schema: &Schema,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>;
+}
+
+/// Implemented once per wire format. Consumed by value, mirroring the shape
+/// of a single CBOR/JSON item: a serializer either turns directly into a
+/// scalar, or hands off to a [`StructSerializer`]/[`ListSerializer`] that
+/// keeps writing into the same underlying buffer.
+pub trait Serializer: Sized {
+    type Ok;
+    type Error: std::error::Error;
+    type SerializeStruct: StructSerializer<Ok = Self::Ok, Error = Self::Error>;
+    type SerializeList: ListSerializer<Ok = Self::Ok, Error = Self::Error>;
+
+    /// `size` must be the number of members this struct will actually write
+    /// (i.e. excluding absent optionals), since formats with definite-length
+    /// framing (CBOR) can't be patched after the fact.
+    fn serialize_struct(self, schema: &Schema, size: usize) -> Result<Self::SerializeStruct, Self::Error>;
+    fn serialize_list(self, schema: &Schema, size: usize) -> Result<Self::SerializeList, Self::Error>;
+    fn serialize_boolean(self, value: bool) -> Result<Self::Ok, Self::Error>;
+    fn serialize_integer(self, value: i32) -> Result<Self::Ok, Self::Error>;
+    fn serialize_long(self, value: i64) -> Result<Self::Ok, Self::Error>;
+    fn serialize_float(self, value: f32) -> Result<Self::Ok, Self::Error>;
+    fn serialize_double(self, value: f64) -> Result<Self::Ok, Self::Error>;
+    fn serialize_string(self, value: &str) -> Result<Self::Ok, Self::Error>;
+    fn serialize_blob(self, value: &[u8]) -> Result<Self::Ok, Self::Error>;
+    fn serialize_null(self) -> Result<Self::Ok, Self::Error>;
+}
+
+pub trait StructSerializer {
+    type Ok;
+    type Error: std::error::Error;
+
+    /// `wire_name` is passed explicitly (rather than read off
+    /// `member_schema`) so the same method serves both a structure's
+    /// statically-known members and a map's dynamically-keyed entries.
+    fn serialize_member<T: ?Sized + SerializeWithSchema>(
+        &mut self,
+        wire_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error>;
+
+    fn end(self) -> Result<Self::Ok, Self::Error>;
+}
+
+pub trait ListSerializer {
+    type Ok;
+    type Error: std::error::Error;
+
+    fn serialize_element<T: ?Sized + SerializeWithSchema>(
+        &mut self,
+        element_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error>;
+
+    fn end(self) -> Result<Self::Ok, Self::Error>;
+}
+
+impl SerializeWithSchema for bool {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_boolean(*self)
+    }
+}
+
+impl SerializeWithSchema for i32 {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_integer(*self)
+    }
+}
+
+impl SerializeWithSchema for i64 {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_long(*self)
+    }
+}
+
+impl SerializeWithSchema for f32 {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_float(*self)
+    }
+}
+
+impl SerializeWithSchema for f64 {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_double(*self)
+    }
+}
+
+impl SerializeWithSchema for str {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_string(self)
+    }
+}
+
+impl SerializeWithSchema for String {
+    fn serialize_with_schema<S: Serializer>(&self, schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_str().serialize_with_schema(schema, serializer)
+    }
+}
+
+impl SerializeWithSchema for [u8] {
+    fn serialize_with_schema<S: Serializer>(&self, _schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_blob(self)
+    }
+}
+
+impl<T: SerializeWithSchema> SerializeWithSchema for Vec<T> {
+    fn serialize_with_schema<S: Serializer>(&self, schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        let element_schema = schema
+            .element()
+            .expect("list schema must declare an element type");
+        let mut list_ser = serializer.serialize_list(schema, self.len())?;
+        for item in self {
+            list_ser.serialize_element(element_schema, item)?;
+        }
+        list_ser.end()
+    }
+}
+
+impl SerializeWithSchema for IndexMap<String, String> {
+    fn serialize_with_schema<S: Serializer>(&self, schema: &Schema, serializer: S) -> Result<S::Ok, S::Error> {
+        let value_schema = schema
+            .map_value()
+            .expect("map schema must declare a value type");
+        let mut struct_ser = serializer.serialize_struct(schema, self.len())?;
+        for (key, value) in self {
+            struct_ser.serialize_member(key, value_schema, value)?;
+        }
+        struct_ser.end()
+    }
+}
@@ -0,0 +1,73 @@
+/// Defines one or more `const Schema`s at compile time, mirroring how a
+/// Smithy model declares a shape. Three forms are supported, matching the
+/// shape kinds codecs need to drive generically:
+///
+/// ```ignore
+/// smithy!("com.example#Tags": {
+///     list TAG_LIST_SCHEMA {
+///         member: STRING
+///     }
+/// });
+///
+/// smithy!("com.example#Attributes": {
+///     map ATTRIBUTE_MAP_SCHEMA {
+///         key: STRING,
+///         value: STRING
+///     }
+/// });
+///
+/// smithy!("com.example#Widget": {
+///     structure WIDGET_SCHEMA {
+///         NAME: STRING = "name"
+///     }
+/// });
+/// ```
+///
+/// For a `structure`, each member line also defines a `const` (here
+/// `NAME`) carrying that member's wire name, for `#[smithy_schema(NAME)]`
+/// to reference from the corresponding `#[derive(SmithyShape)]` field.
+#[macro_export]
+macro_rules! smithy {
+    ($name:literal : { list $ident:ident { member: $member_ty:path } }) => {
+        pub const $ident: $crate::schema::Schema = $crate::schema::Schema(&$crate::schema::SchemaData {
+            shape_type: $crate::schema::ShapeType::List,
+            name: $name,
+            wire_name: "",
+            optional: false,
+            members: &[],
+            element: Some(&$member_ty),
+            map_key: None,
+            map_value: None,
+        });
+    };
+    ($name:literal : { map $ident:ident { key: $key_ty:path, value: $value_ty:path } }) => {
+        pub const $ident: $crate::schema::Schema = $crate::schema::Schema(&$crate::schema::SchemaData {
+            shape_type: $crate::schema::ShapeType::Map,
+            name: $name,
+            wire_name: "",
+            optional: false,
+            members: &[],
+            element: None,
+            map_key: Some(&$key_ty),
+            map_value: Some(&$value_ty),
+        });
+    };
+    ($name:literal : { structure $ident:ident { $($member:ident : $member_ty:path = $wire:literal)* } }) => {
+        $(
+            pub const $member: $crate::schema::Schema = $crate::schema::Schema(&$crate::schema::SchemaData {
+                wire_name: $wire,
+                ..*$member_ty.0
+            });
+        )*
+        pub const $ident: $crate::schema::Schema = $crate::schema::Schema(&$crate::schema::SchemaData {
+            shape_type: $crate::schema::ShapeType::Structure,
+            name: $name,
+            wire_name: "",
+            optional: false,
+            members: &[ $($member),* ],
+            element: None,
+            map_key: None,
+            map_value: None,
+        });
+    };
+}
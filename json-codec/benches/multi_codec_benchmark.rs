@@ -0,0 +1,401 @@
+//! Comparative multi-codec benchmark harness.
+//!
+//! Runs the same `BenchmarkRecord` payload through every available codec
+//! (JSON and CBOR) and emits one results table instead of
+//! only Criterion's per-function output, so results are queryable and
+//! diffable across runs.
+//!
+//! Run with: cargo bench -p smithy4rs-json-codec --bench multi_codec_benchmark
+//! Compare against a prior run: cargo bench -p smithy4rs-json-codec --bench multi_codec_benchmark -- --baseline run.json
+//!
+//! This target opts out of the Criterion harness (`harness = false` in
+//! `Cargo.toml`) so it can parse `--baseline` itself and print a single
+//! combined table instead of one `criterion_group` per function.
+
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use smithy4rs_core::{
+    derive::SmithyShape,
+    schema::prelude::{BOOLEAN, DOUBLE, FLOAT, INTEGER, LONG, STRING},
+    serde::{
+        de::{DeserializeWithSchema, Deserializer},
+        serializers::SerializeWithSchema,
+        ShapeBuilder,
+    },
+    smithy,
+};
+use smithy4rs_cbor_codec::{CborDeserializer, CborSerializer};
+use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
+
+const SAMPLE_PAYLOAD: &[u8] = include_bytes!("sample_payload.json");
+const BENCHMARK_ITERATIONS: usize = 200;
+const REGRESSION_THRESHOLD_PCT: f64 = 5.0;
+
+// ============================================================================
+// Schema Definitions (a subset of serde_benchmark.rs's BenchmarkRecord)
+// ============================================================================
+
+smithy!("com.benchmark#TagList": {
+    list TAG_LIST_SCHEMA {
+        member: STRING
+    }
+});
+
+smithy!("com.benchmark#BenchmarkRecord": {
+    structure BENCHMARK_RECORD_SCHEMA {
+        ID: STRING = "id"
+        NAME: STRING = "name"
+        PRICE: DOUBLE = "price"
+        QUANTITY: INTEGER = "quantity"
+        RATING: FLOAT = "rating"
+        IS_AVAILABLE: BOOLEAN = "isAvailable"
+        CREATED_AT: LONG = "createdAt"
+        TAGS: TAG_LIST_SCHEMA = "tags"
+    }
+});
+
+smithy!("com.benchmark#RunResult": {
+    structure RUN_RESULT_SCHEMA {
+        CODEC: STRING = "codec"
+        OPERATION: STRING = "operation"
+        PAYLOAD_SIZE_BYTES: LONG = "payloadSizeBytes"
+        THROUGHPUT_BYTES_PER_SEC: DOUBLE = "throughputBytesPerSec"
+        MEAN_NANOS: DOUBLE = "meanNanos"
+        MEDIAN_NANOS: DOUBLE = "medianNanos"
+        MIN_NANOS: LONG = "minNanos"
+        MAX_NANOS: LONG = "maxNanos"
+        VARIANCE_NANOS2: DOUBLE = "varianceNanos2"
+        SAMPLE_COUNT: INTEGER = "sampleCount"
+        GIT_COMMIT: STRING = "gitCommit"
+        TIMESTAMP: STRING = "timestamp"
+    }
+});
+
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(BENCHMARK_RECORD_SCHEMA)]
+pub struct BenchmarkRecord {
+    #[smithy_schema(ID)]
+    pub id: String,
+    #[smithy_schema(NAME)]
+    pub name: String,
+    #[smithy_schema(PRICE)]
+    pub price: f64,
+    #[smithy_schema(QUANTITY)]
+    pub quantity: i32,
+    #[smithy_schema(RATING)]
+    pub rating: Option<f32>,
+    #[smithy_schema(IS_AVAILABLE)]
+    pub is_available: Option<bool>,
+    #[smithy_schema(CREATED_AT)]
+    pub created_at: Option<i64>,
+    #[smithy_schema(TAGS)]
+    pub tags: Option<Vec<String>>,
+}
+
+/// One row of the results table. A `#[derive(SmithyShape)]` type so the
+/// crate dogfoods its own serialization when persisting a run to disk.
+#[derive(SmithyShape, Clone, PartialEq)]
+#[smithy_schema(RUN_RESULT_SCHEMA)]
+pub struct RunResult {
+    #[smithy_schema(CODEC)]
+    pub codec: String,
+    #[smithy_schema(OPERATION)]
+    pub operation: String,
+    #[smithy_schema(PAYLOAD_SIZE_BYTES)]
+    pub payload_size_bytes: i64,
+    #[smithy_schema(THROUGHPUT_BYTES_PER_SEC)]
+    pub throughput_bytes_per_sec: f64,
+    #[smithy_schema(MEAN_NANOS)]
+    pub mean_nanos: f64,
+    #[smithy_schema(MEDIAN_NANOS)]
+    pub median_nanos: f64,
+    #[smithy_schema(MIN_NANOS)]
+    pub min_nanos: i64,
+    #[smithy_schema(MAX_NANOS)]
+    pub max_nanos: i64,
+    #[smithy_schema(VARIANCE_NANOS2)]
+    pub variance_nanos2: f64,
+    #[smithy_schema(SAMPLE_COUNT)]
+    pub sample_count: i32,
+    #[smithy_schema(GIT_COMMIT)]
+    pub git_commit: String,
+    #[smithy_schema(TIMESTAMP)]
+    pub timestamp: String,
+}
+
+// ============================================================================
+// Sample Data Loading
+// ============================================================================
+
+fn load_sample_record() -> BenchmarkRecord {
+    let mut deserializer = JsonDeserializer::new(SAMPLE_PAYLOAD);
+    BenchmarkRecordBuilder::deserialize_with_schema(&BENCHMARK_RECORD_SCHEMA, &mut deserializer)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+// ============================================================================
+// Timing
+// ============================================================================
+
+/// Mean, median, min, max, variance and sample count for one (codec,
+/// operation) pair, computed from raw per-iteration timings.
+struct Stats {
+    mean: Duration,
+    median: Duration,
+    min: Duration,
+    max: Duration,
+    variance_nanos2: f64,
+    samples: usize,
+}
+
+fn compute_stats(mut durations: Vec<Duration>) -> Stats {
+    durations.sort();
+    let samples = durations.len();
+    let total_nanos: u128 = durations.iter().map(|d| d.as_nanos()).sum();
+    let mean_nanos = total_nanos as f64 / samples as f64;
+    let variance_nanos2 = durations
+        .iter()
+        .map(|d| {
+            let delta = d.as_nanos() as f64 - mean_nanos;
+            delta * delta
+        })
+        .sum::<f64>()
+        / samples as f64;
+
+    Stats {
+        mean: Duration::from_nanos(mean_nanos as u64),
+        median: durations[samples / 2],
+        min: durations[0],
+        max: durations[samples - 1],
+        variance_nanos2,
+        samples,
+    }
+}
+
+fn time_iterations<F: FnMut()>(mut f: F, count: usize) -> Vec<Duration> {
+    (0..count)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect()
+}
+
+// ============================================================================
+// Per-codec runs
+// ============================================================================
+
+fn run_json(record: &BenchmarkRecord, timestamp: &str, git_commit: &str) -> Vec<RunResult> {
+    let mut payload = Vec::new();
+    record
+        .serialize_with_schema(&BENCHMARK_RECORD_SCHEMA, JsonSerializer::new(&mut payload))
+        .unwrap();
+    let payload_size = payload.len();
+
+    let serialize_stats = compute_stats(time_iterations(
+        || {
+            let mut buf = Vec::with_capacity(payload_size);
+            record
+                .serialize_with_schema(&BENCHMARK_RECORD_SCHEMA, JsonSerializer::new(&mut buf))
+                .unwrap();
+            black_box(buf);
+        },
+        BENCHMARK_ITERATIONS,
+    ));
+
+    let deserialize_stats = compute_stats(time_iterations(
+        || {
+            let mut deserializer = JsonDeserializer::new(black_box(&payload));
+            let result = BenchmarkRecordBuilder::deserialize_with_schema(
+                &BENCHMARK_RECORD_SCHEMA,
+                &mut deserializer,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+            black_box(result);
+        },
+        BENCHMARK_ITERATIONS,
+    ));
+
+    vec![
+        to_run_result("json", "serialize", payload_size, serialize_stats, timestamp, git_commit),
+        to_run_result("json", "deserialize", payload_size, deserialize_stats, timestamp, git_commit),
+    ]
+}
+
+fn run_cbor(record: &BenchmarkRecord, timestamp: &str, git_commit: &str) -> Vec<RunResult> {
+    let mut payload = Vec::new();
+    record
+        .serialize_with_schema(&BENCHMARK_RECORD_SCHEMA, CborSerializer::new(&mut payload))
+        .unwrap();
+    let payload_size = payload.len();
+
+    let serialize_stats = compute_stats(time_iterations(
+        || {
+            let mut buf = Vec::with_capacity(payload_size);
+            record
+                .serialize_with_schema(&BENCHMARK_RECORD_SCHEMA, CborSerializer::new(&mut buf))
+                .unwrap();
+            black_box(buf);
+        },
+        BENCHMARK_ITERATIONS,
+    ));
+
+    let deserialize_stats = compute_stats(time_iterations(
+        || {
+            let mut deserializer = CborDeserializer::new(black_box(&payload));
+            let result = BenchmarkRecordBuilder::deserialize_with_schema(
+                &BENCHMARK_RECORD_SCHEMA,
+                &mut deserializer,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+            black_box(result);
+        },
+        BENCHMARK_ITERATIONS,
+    ));
+
+    vec![
+        to_run_result("cbor", "serialize", payload_size, serialize_stats, timestamp, git_commit),
+        to_run_result("cbor", "deserialize", payload_size, deserialize_stats, timestamp, git_commit),
+    ]
+}
+
+fn to_run_result(
+    codec: &str,
+    operation: &str,
+    payload_size: usize,
+    stats: Stats,
+    timestamp: &str,
+    git_commit: &str,
+) -> RunResult {
+    RunResult {
+        codec: codec.to_string(),
+        operation: operation.to_string(),
+        payload_size_bytes: payload_size as i64,
+        throughput_bytes_per_sec: payload_size as f64 / stats.mean.as_secs_f64(),
+        mean_nanos: stats.mean.as_nanos() as f64,
+        median_nanos: stats.median.as_nanos() as f64,
+        min_nanos: stats.min.as_nanos() as i64,
+        max_nanos: stats.max.as_nanos() as i64,
+        variance_nanos2: stats.variance_nanos2,
+        sample_count: stats.samples as i32,
+        git_commit: git_commit.to_string(),
+        timestamp: timestamp.to_string(),
+    }
+}
+
+// ============================================================================
+// Reporting
+// ============================================================================
+
+fn print_table(results: &[RunResult], baseline: Option<&[RunResult]>) {
+    if baseline.is_some() {
+        println!("| Codec | Operation | Mean (ns) | Median (ns) | Min (ns) | Max (ns) | Variance | Samples | Δ Mean % |");
+        println!("|---|---|---|---|---|---|---|---|---|");
+    } else {
+        println!("| Codec | Operation | Mean (ns) | Median (ns) | Min (ns) | Max (ns) | Variance | Samples |");
+        println!("|---|---|---|---|---|---|---|---|");
+    }
+
+    for result in results {
+        let baseline_row = baseline.and_then(|rows| {
+            rows.iter()
+                .find(|row| row.codec == result.codec && row.operation == result.operation)
+        });
+
+        let row = format!(
+            "| {} | {} | {:.1} | {:.1} | {} | {} | {:.1} | {} |",
+            result.codec,
+            result.operation,
+            result.mean_nanos,
+            result.median_nanos,
+            result.min_nanos,
+            result.max_nanos,
+            result.variance_nanos2,
+            result.sample_count,
+        );
+
+        match baseline_row {
+            Some(baseline_row) => {
+                let pct_change =
+                    (result.mean_nanos - baseline_row.mean_nanos) / baseline_row.mean_nanos * 100.0;
+                let flag = if pct_change >= REGRESSION_THRESHOLD_PCT {
+                    " ⚠"
+                } else {
+                    ""
+                };
+                println!("{row} {pct_change:+.1}%{flag} |");
+            }
+            // No matching (codec, operation) in the baseline (e.g. a
+            // pre-CBOR baseline file) — still emit the Δ column so every
+            // row has the same cell count as the header.
+            None if baseline.is_some() => println!("{row} n/a |"),
+            None => println!("{row}"),
+        }
+    }
+}
+
+fn persist(results: &[RunResult], path: &str) {
+    let mut records = Vec::new();
+    for result in results {
+        let mut buf = Vec::new();
+        result
+            .serialize_with_schema(&RUN_RESULT_SCHEMA, JsonSerializer::new(&mut buf))
+            .unwrap();
+        records.push(String::from_utf8(buf).unwrap());
+    }
+    let document = format!("[{}]", records.join(","));
+    fs::write(path, document).expect("failed to write benchmark results");
+}
+
+fn load_baseline(path: &str) -> Vec<RunResult> {
+    let document = fs::read_to_string(path).expect("failed to read baseline file");
+    let mut deserializer = JsonDeserializer::new(document.as_bytes());
+    let mut results = Vec::new();
+    deserializer
+        .deserialize_list(&RUN_RESULT_SCHEMA, |de| {
+            let result = RunResultBuilder::deserialize_with_schema(&RUN_RESULT_SCHEMA, de)?
+                .build()
+                .unwrap();
+            results.push(result);
+            Ok(())
+        })
+        .expect("baseline file is not a valid results array");
+    results
+}
+
+// ============================================================================
+// Entry point
+// ============================================================================
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let baseline_path = args
+        .iter()
+        .position(|arg| arg == "--baseline")
+        .and_then(|index| args.get(index + 1))
+        .cloned();
+
+    let record = load_sample_record();
+    let timestamp = env::var("SMITHY4RS_BENCH_TIMESTAMP").unwrap_or_else(|_| "unknown".to_string());
+    let git_commit = env::var("SMITHY4RS_BENCH_GIT_COMMIT").unwrap_or_else(|_| "unknown".to_string());
+
+    let mut results = run_json(&record, &timestamp, &git_commit);
+    results.extend(run_cbor(&record, &timestamp, &git_commit));
+
+    let baseline = baseline_path.as_deref().map(load_baseline);
+    print_table(&results, baseline.as_deref());
+
+    let output_path = format!("bench_results_{timestamp}.json");
+    persist(&results, &output_path);
+    println!("\nWrote results to {output_path}");
+}
@@ -8,7 +8,11 @@ use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 use smithy4rs_core::{
     derive::SmithyShape,
     schema::prelude::{BOOLEAN, DOUBLE, FLOAT, INTEGER, LONG, STRING},
-    serde::{ShapeBuilder, de::DeserializeWithSchema, serializers::SerializeWithSchema},
+    serde::{
+        ShapeBuilder,
+        de::{DeserializeWithSchema, Deserializer},
+        serializers::SerializeWithSchema,
+    },
     smithy, IndexMap,
 };
 use smithy4rs_json_codec::{JsonDeserializer, JsonSerializer};
@@ -45,7 +49,7 @@ smithy!("com.benchmark#FlagList": {
 
 smithy!("com.benchmark#AttributeMap": {
     map ATTRIBUTE_MAP_SCHEMA {
-        key: STRING
+        key: STRING,
         value: STRING
     }
 });
@@ -219,6 +223,104 @@ fn benchmark_deserialization(c: &mut Criterion) {
     group.finish();
 }
 
+/// Companion to `deserialize_benchmark_record` that parses the same full set
+/// of members but routes every string-typed member through
+/// `JsonDeserializer::deserialize_str`'s `Cow`-borrowing fast path instead of
+/// `DeserializeWithSchema`'s owned-`String` path, to quantify the allocation
+/// savings on an apples-to-apples workload.
+fn benchmark_deserialize_record_borrowed(c: &mut Criterion) {
+    let record = load_sample_record();
+
+    let mut buf = Vec::new();
+    let serializer = JsonSerializer::new(&mut buf);
+    record
+        .serialize_with_schema(&BENCHMARK_RECORD_SCHEMA, serializer)
+        .unwrap();
+    let json_bytes = buf;
+    let payload_size = json_bytes.len();
+
+    let mut group = c.benchmark_group("deserialization");
+    group.throughput(Throughput::Bytes(payload_size as u64));
+
+    group.bench_function("deserialize_benchmark_record_borrowed", |b| {
+        b.iter(|| {
+            let mut deserializer = JsonDeserializer::new(black_box(&json_bytes));
+            let mut total_len = 0usize;
+            deserializer
+                .deserialize_struct(&BENCHMARK_RECORD_SCHEMA, |de, key| {
+                    match key {
+                        "id" | "name" | "description" | "category" => {
+                            total_len += de.deserialize_str(&STRING)?.len();
+                        }
+                        "price" => {
+                            de.deserialize_double(&DOUBLE)?;
+                        }
+                        "quantity" => {
+                            de.deserialize_integer(&INTEGER)?;
+                        }
+                        "rating" => {
+                            de.deserialize_float(&FLOAT)?;
+                        }
+                        "isAvailable" => {
+                            de.deserialize_boolean(&BOOLEAN)?;
+                        }
+                        "createdAt" | "updatedAt" => {
+                            de.deserialize_long(&LONG)?;
+                        }
+                        "tags" | "relatedIds" => {
+                            de.deserialize_list(&TAG_LIST_SCHEMA, |de| {
+                                total_len += de.deserialize_str(&STRING)?.len();
+                                Ok(())
+                            })?;
+                        }
+                        "attributes" => {
+                            de.deserialize_struct(&ATTRIBUTE_MAP_SCHEMA, |de, attr_key| {
+                                total_len += attr_key.len();
+                                total_len += de.deserialize_str(&STRING)?.len();
+                                Ok(true)
+                            })?;
+                        }
+                        "metadata" => {
+                            de.deserialize_struct(&RECORD_METADATA_SCHEMA, |de, meta_key| {
+                                match meta_key {
+                                    "version" | "source" | "checksum" => {
+                                        total_len += de.deserialize_str(&STRING)?.len();
+                                    }
+                                    "priority" => {
+                                        de.deserialize_integer(&INTEGER)?;
+                                    }
+                                    "weight" => {
+                                        de.deserialize_double(&DOUBLE)?;
+                                    }
+                                    "flags" => {
+                                        de.deserialize_list(&FLAG_LIST_SCHEMA, |de| {
+                                            de.deserialize_boolean(&BOOLEAN)?;
+                                            Ok(())
+                                        })?;
+                                    }
+                                    _ => return Ok(false),
+                                }
+                                Ok(true)
+                            })?;
+                        }
+                        "scores" => {
+                            de.deserialize_list(&SCORE_LIST_SCHEMA, |de| {
+                                de.deserialize_double(&DOUBLE)?;
+                                Ok(())
+                            })?;
+                        }
+                        _ => return Ok(false),
+                    }
+                    Ok(true)
+                })
+                .unwrap();
+            black_box(total_len)
+        })
+    });
+
+    group.finish();
+}
+
 fn benchmark_roundtrip(c: &mut Criterion) {
     let record = load_sample_record();
 
@@ -262,6 +364,7 @@ criterion_group!(
     benches,
     benchmark_serialization,
     benchmark_deserialization,
+    benchmark_deserialize_record_borrowed,
     benchmark_roundtrip
 );
 criterion_main!(benches);
@@ -0,0 +1,410 @@
+//! JSON deserialization, including a borrowing fast path for strings.
+
+use std::borrow::Cow;
+
+use smithy4rs_core::schema::prelude::Schema;
+use smithy4rs_core::serde::de::{DeserializeError, Deserializer};
+
+/// Deserializes Smithy shapes from a JSON buffer. Its inherent
+/// [`deserialize_str`](Self::deserialize_str) borrows unescaped strings from
+/// `input` rather than copying them; the `Deserializer` trait's
+/// `deserialize_string`, which generated builders call, always returns an
+/// owned copy.
+pub struct JsonDeserializer<'de> {
+    input: &'de [u8],
+    pos: usize,
+}
+
+impl<'de> JsonDeserializer<'de> {
+    pub fn new(input: &'de [u8]) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.input.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes `literal` (e.g. `b"true"`, `b"null"`) if it appears at the
+    /// current position, erroring rather than panicking on a truncated or
+    /// garbled literal instead of blindly advancing past it.
+    fn consume_literal(&mut self, literal: &[u8]) -> Result<(), DeserializeError> {
+        let matches = self.input.get(self.pos..self.pos + literal.len()) == Some(literal)
+            && !matches!(
+                self.input.get(self.pos + literal.len()),
+                Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9')
+            );
+        if matches {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(DeserializeError::new(format!(
+                "expected literal {:?} at byte {}",
+                std::str::from_utf8(literal).unwrap_or("?"),
+                self.pos
+            )))
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), DeserializeError> {
+        self.skip_whitespace();
+        if self.input.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(DeserializeError::new(format!(
+                "expected '{}' at byte {}",
+                byte as char, self.pos
+            )))
+        }
+    }
+
+    /// Scans a JSON string literal starting at the opening quote. Returns a
+    /// borrowed slice of `input` when the string contains no `\`-escapes,
+    /// and only allocates an owned, unescaped `String` when it does.
+    /// `\uXXXX` escapes are not decoded: a string containing one is
+    /// rejected with an error.
+    fn parse_str_cow(&mut self) -> Result<Cow<'de, str>, DeserializeError> {
+        self.expect(b'"')?;
+        let start = self.pos;
+
+        // Fast path: scan for the closing quote, bailing to the slow path
+        // the moment we see a backslash.
+        let mut i = self.pos;
+        loop {
+            match self.input.get(i) {
+                Some(b'"') => {
+                    let borrowed = std::str::from_utf8(&self.input[start..i])
+                        .map_err(|_| DeserializeError::new("invalid UTF-8 in JSON string"))?;
+                    self.pos = i + 1;
+                    return Ok(Cow::Borrowed(borrowed));
+                }
+                Some(b'\\') => break,
+                Some(_) => i += 1,
+                None => return Err(DeserializeError::new("unterminated JSON string")),
+            }
+        }
+
+        // Slow path: unescape into an owned buffer.
+        let mut owned = String::from_utf8(self.input[start..i].to_vec())
+            .map_err(|_| DeserializeError::new("invalid UTF-8 in JSON string"))?;
+        self.pos = i;
+        loop {
+            match self.input.get(self.pos) {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(Cow::Owned(owned));
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = *self
+                        .input
+                        .get(self.pos)
+                        .ok_or_else(|| DeserializeError::new("unterminated JSON escape"))?;
+                    owned.push(match escaped {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'n' => '\n',
+                        b't' => '\t',
+                        b'r' => '\r',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'u' => return Err(DeserializeError::new("\\u escapes are not supported")),
+                        other => {
+                            return Err(DeserializeError::new(format!(
+                                "invalid JSON escape '\\{}'",
+                                other as char
+                            )))
+                        }
+                    });
+                    self.pos += 1;
+                }
+                Some(_) => {
+                    let run_start = self.pos;
+                    while !matches!(self.input.get(self.pos), Some(b'"' | b'\\') | None) {
+                        self.pos += 1;
+                    }
+                    let run = std::str::from_utf8(&self.input[run_start..self.pos])
+                        .map_err(|_| DeserializeError::new("invalid UTF-8 in JSON string"))?;
+                    owned.push_str(run);
+                }
+                None => return Err(DeserializeError::new("unterminated JSON string")),
+            }
+        }
+    }
+
+    /// Borrowing counterpart to `Deserializer::deserialize_string`: returns
+    /// a `Cow<'de, str>` instead of always allocating. It is an inherent
+    /// method, not part of the `Deserializer` trait, so generated
+    /// `ShapeBuilder`s never call it; only code holding a `JsonDeserializer`
+    /// directly gets the borrowed path.
+    pub fn deserialize_str(&mut self, _schema: &Schema) -> Result<Cow<'de, str>, DeserializeError> {
+        self.parse_str_cow()
+    }
+}
+
+impl<'de> Deserializer<'de> for JsonDeserializer<'de> {
+    type Error = DeserializeError;
+
+    fn deserialize_boolean(&mut self, _schema: &Schema) -> Result<bool, Self::Error> {
+        self.skip_whitespace();
+        if self.consume_literal(b"true").is_ok() {
+            Ok(true)
+        } else if self.consume_literal(b"false").is_ok() {
+            Ok(false)
+        } else {
+            Err(DeserializeError::new("expected a JSON boolean"))
+        }
+    }
+
+    fn deserialize_integer(&mut self, schema: &Schema) -> Result<i32, Self::Error> {
+        self.deserialize_long(schema).map(|v| v as i32)
+    }
+
+    fn deserialize_long(&mut self, _schema: &Schema) -> Result<i64, Self::Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.input.get(self.pos) == Some(&b'-') {
+            self.pos += 1;
+        }
+        while matches!(self.input.get(self.pos), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| DeserializeError::new("expected a JSON integer"))
+    }
+
+    fn deserialize_float(&mut self, schema: &Schema) -> Result<f32, Self::Error> {
+        self.deserialize_double(schema).map(|v| v as f32)
+    }
+
+    fn deserialize_double(&mut self, _schema: &Schema) -> Result<f64, Self::Error> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while matches!(
+            self.input.get(self.pos),
+            Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+        ) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.input[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| DeserializeError::new("expected a JSON number"))
+    }
+
+    fn deserialize_string(&mut self, schema: &Schema) -> Result<String, Self::Error> {
+        self.deserialize_str(schema).map(Cow::into_owned)
+    }
+
+    fn deserialize_blob(&mut self, schema: &Schema) -> Result<Vec<u8>, Self::Error> {
+        // Blobs are base64-encoded JSON strings; decoding always allocates,
+        // so there is no borrowing fast path here.
+        let text = self.deserialize_string(schema)?;
+        base64_decode(&text)
+            .ok_or_else(|| DeserializeError::new("invalid base64 in JSON blob"))
+    }
+
+    fn is_null(&mut self) -> Result<bool, Self::Error> {
+        self.skip_whitespace();
+        Ok(self.consume_literal(b"null").is_ok())
+    }
+
+    fn deserialize_struct(
+        &mut self,
+        _schema: &Schema,
+        mut visit_member: impl FnMut(&mut Self, &str) -> Result<bool, Self::Error>,
+    ) -> Result<(), Self::Error> {
+        self.expect(b'{')?;
+        self.skip_whitespace();
+        if self.input.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            let key = self.parse_str_cow()?;
+            self.expect(b':')?;
+            if !visit_member(self, &key)? {
+                self.skip_value()?;
+            }
+            self.skip_whitespace();
+            match self.input.get(self.pos) {
+                Some(b',') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                }
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return Err(DeserializeError::new("expected ',' or '}' in JSON object")),
+            }
+        }
+    }
+
+    fn deserialize_list(
+        &mut self,
+        _schema: &Schema,
+        mut visit_element: impl FnMut(&mut Self) -> Result<(), Self::Error>,
+    ) -> Result<(), Self::Error> {
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.input.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            visit_element(self)?;
+            self.skip_whitespace();
+            match self.input.get(self.pos) {
+                Some(b',') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                }
+                Some(b']') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return Err(DeserializeError::new("expected ',' or ']' in JSON array")),
+            }
+        }
+    }
+}
+
+impl<'de> JsonDeserializer<'de> {
+    /// Skips a single JSON value, used to discard members with an unknown
+    /// wire name. Operates on the raw scanner directly rather than through
+    /// the schema-driven `Deserializer` methods, since there is no schema
+    /// for an unrecognized member.
+    fn skip_value(&mut self) -> Result<(), DeserializeError> {
+        self.skip_whitespace();
+        match self.input.get(self.pos) {
+            Some(b'"') => {
+                self.parse_str_cow()?;
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.input.get(self.pos) == Some(&b'}') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.parse_str_cow()?;
+                        self.expect(b':')?;
+                        self.skip_value()?;
+                        self.skip_whitespace();
+                        match self.input.get(self.pos) {
+                            Some(b',') => {
+                                self.pos += 1;
+                                self.skip_whitespace();
+                            }
+                            Some(b'}') => {
+                                self.pos += 1;
+                                break;
+                            }
+                            _ => return Err(DeserializeError::new("expected ',' or '}' in JSON object")),
+                        }
+                    }
+                }
+            }
+            Some(b'[') => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.input.get(self.pos) == Some(&b']') {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.skip_value()?;
+                        self.skip_whitespace();
+                        match self.input.get(self.pos) {
+                            Some(b',') => {
+                                self.pos += 1;
+                                self.skip_whitespace();
+                            }
+                            Some(b']') => {
+                                self.pos += 1;
+                                break;
+                            }
+                            _ => return Err(DeserializeError::new("expected ',' or ']' in JSON array")),
+                        }
+                    }
+                }
+            }
+            Some(b't') => self.consume_literal(b"true")?,
+            Some(b'f') => self.consume_literal(b"false")?,
+            Some(b'n') => self.consume_literal(b"null")?,
+            Some(_) => {
+                while matches!(
+                    self.input.get(self.pos),
+                    Some(b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+                ) {
+                    self.pos += 1;
+                }
+            }
+            None => return Err(DeserializeError::new("unexpected end of JSON input")),
+        }
+        Ok(())
+    }
+}
+
+/// Standard (non-URL-safe) base64 decoding, unchanged by the borrowing work
+/// in this module — blobs always allocate since decoding can't avoid a copy.
+pub(crate) fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    fn value(byte: u8) -> Option<u8> {
+        match byte {
+            b'A'..=b'Z' => Some(byte - b'A'),
+            b'a'..=b'z' => Some(byte - b'a' + 26),
+            b'0'..=b'9' => Some(byte - b'0' + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let input = input.trim_end_matches('=');
+    let mut out = Vec::with_capacity(input.len() * 3 / 4);
+    let mut chunks = input.as_bytes().chunks(4);
+    for chunk in &mut chunks {
+        let values: Vec<u8> = chunk.iter().map(|&b| value(b)).collect::<Option<_>>()?;
+        out.push((values[0] << 2) | (values.get(1).copied().unwrap_or(0) >> 4));
+        if values.len() > 2 {
+            out.push((values[1] << 4) | (values[2] >> 2));
+        }
+        if values.len() > 3 {
+            out.push((values[2] << 6) | values[3]);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skip_value_rejects_a_truncated_literal_instead_of_desyncing() {
+        // "tru" is not a complete `true`, and sits at the very end of the
+        // buffer, so blindly advancing by 4 would push `pos` past
+        // `input.len()`.
+        let mut de = JsonDeserializer::new(b"tru");
+        assert!(de.skip_value().is_err());
+    }
+
+    #[test]
+    fn skip_value_rejects_a_garbled_literal() {
+        let mut de = JsonDeserializer::new(b"nully");
+        assert!(de.skip_value().is_err());
+    }
+
+    #[test]
+    fn skip_value_accepts_well_formed_literals() {
+        for literal in [&b"true"[..], b"false", b"null"] {
+            let mut de = JsonDeserializer::new(literal);
+            assert!(de.skip_value().is_ok());
+        }
+    }
+}
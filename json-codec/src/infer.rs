@@ -0,0 +1,221 @@
+//! Runtime [`Schema`] inference from a sample JSON document.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+use smithy4rs_core::schema::prelude::{Schema, SchemaBuilder, ShapeType};
+use smithy4rs_core::serde::de::DeserializeError;
+
+/// The name given to the top-level structure produced by [`infer_schema`].
+const ROOT_STRUCTURE_NAME: &str = "com.inferred#Document";
+
+/// An inferred type for one JSON node, before it has been lowered into a
+/// [`Schema`]. Kept separate from `Schema` because inference needs to merge
+/// and widen types across sibling observations before a schema can be built.
+#[derive(Clone, Debug, PartialEq)]
+enum Inferred {
+    Boolean,
+    Long,
+    Double,
+    String,
+    /// Genuinely incompatible observations (e.g. object vs. string) fall
+    /// back to an untyped document rather than failing inference.
+    Document,
+    List(Box<Inferred>),
+    /// Ordered so two structurally identical objects produce the same key
+    /// order and can be deduplicated into one reused schema.
+    Structure(BTreeMap<String, Member>),
+    /// The element type of an empty array, or the type of an all-null
+    /// member: there's no observation to infer from, so it defaults to
+    /// `STRING`. An all-null member is also marked `optional`; an empty
+    /// array's element carries no `optional` flag.
+    Unresolved,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Member {
+    ty: Inferred,
+    optional: bool,
+}
+
+/// Infers a [`Schema`] describing `bytes`, which must be a single JSON
+/// document (object, array, or scalar) at the top level.
+pub fn infer_schema(bytes: &[u8]) -> Result<Schema, DeserializeError> {
+    let value: Value = serde_json::from_slice(bytes)
+        .map_err(|e| DeserializeError::new(format!("sample payload is not valid JSON: {e}")))?;
+    let inferred = infer_value(&value);
+    let mut cache = BTreeMap::new();
+    Ok(lower(&inferred, ROOT_STRUCTURE_NAME, &mut cache))
+}
+
+fn infer_value(value: &Value) -> Inferred {
+    match value {
+        Value::Null => Inferred::Unresolved,
+        Value::Bool(_) => Inferred::Boolean,
+        Value::Number(n) => {
+            if n.is_i64() || n.is_u64() {
+                Inferred::Long
+            } else {
+                Inferred::Double
+            }
+        }
+        Value::String(_) => Inferred::String,
+        Value::Array(elements) => {
+            let merged = elements
+                .iter()
+                .map(infer_value)
+                .fold(None, |acc, next| match acc {
+                    None => Some(next),
+                    Some(acc) => Some(merge(acc, next)),
+                })
+                .unwrap_or(Inferred::Unresolved);
+            Inferred::List(Box::new(merged))
+        }
+        Value::Object(fields) => {
+            let members = fields
+                .iter()
+                .map(|(key, value)| {
+                    let optional = value.is_null();
+                    (
+                        key.clone(),
+                        Member {
+                            ty: infer_value(value),
+                            optional,
+                        },
+                    )
+                })
+                .collect();
+            Inferred::Structure(members)
+        }
+    }
+}
+
+/// Merges two type observations for the same position (array elements, or
+/// the same object key seen across multiple sample records), applying the
+/// widening rules: integer widens to double if either side is fractional, a
+/// `null` observation marks the member optional, and incompatible types
+/// fall back to `Document`.
+fn merge(left: Inferred, right: Inferred) -> Inferred {
+    use Inferred::*;
+    match (left, right) {
+        (Unresolved, other) | (other, Unresolved) => other,
+        (a, b) if a == b => a,
+        (Long, Double) | (Double, Long) => Double,
+        (List(a), List(b)) => List(Box::new(merge(*a, *b))),
+        (Structure(mut a), Structure(b)) => {
+            // A key missing from one side is genuinely absent in that
+            // sample, so it must be marked optional even if both sides
+            // agree on its type everywhere they share it.
+            for key in a.keys().cloned().collect::<Vec<_>>() {
+                if !b.contains_key(&key) {
+                    a.get_mut(&key).unwrap().optional = true;
+                }
+            }
+            for (key, member) in b {
+                a.entry(key)
+                    .and_modify(|existing| {
+                        existing.ty = merge(existing.ty.clone(), member.ty.clone());
+                        existing.optional |= member.optional;
+                    })
+                    .or_insert(Member {
+                        optional: true,
+                        ..member
+                    });
+            }
+            Structure(a)
+        }
+        _ => Document,
+    }
+}
+
+/// Lowers an [`Inferred`] type into a [`Schema`], reusing a single structure
+/// schema for every set of structurally identical objects (same key set,
+/// member types and optional flags) seen during inference.
+fn lower(inferred: &Inferred, name: &str, cache: &mut BTreeMap<String, Schema>) -> Schema {
+    match inferred {
+        Inferred::Boolean => SchemaBuilder::scalar(ShapeType::Boolean).build(),
+        Inferred::Long => SchemaBuilder::scalar(ShapeType::Long).build(),
+        Inferred::Double => SchemaBuilder::scalar(ShapeType::Double).build(),
+        Inferred::String | Inferred::Unresolved => SchemaBuilder::scalar(ShapeType::String).build(),
+        Inferred::Document => SchemaBuilder::scalar(ShapeType::Document).build(),
+        Inferred::List(element) => {
+            let element_schema = lower(element, &format!("{name}Member"), cache);
+            SchemaBuilder::list(name).element(element_schema).build()
+        }
+        Inferred::Structure(members) => {
+            let cache_key = structure_cache_key(members);
+            if let Some(existing) = cache.get(&cache_key) {
+                return *existing;
+            }
+            let mut builder = SchemaBuilder::structure(name);
+            for (key, member) in members {
+                let member_schema = lower(&member.ty, &struct_member_name(name, key), cache);
+                builder = builder.member(key, member_schema, member.optional);
+            }
+            let schema = builder.build();
+            cache.insert(cache_key, schema);
+            schema
+        }
+    }
+}
+
+/// A stable key identifying a structure's shape (its key set plus each
+/// member's inferred type and optional flag) so two objects with identical shapes reuse the
+/// same generated schema instead of each minting their own.
+fn structure_cache_key(members: &BTreeMap<String, Member>) -> String {
+    members
+        .iter()
+        .map(|(key, member)| format!("{key}:{:?}:{}", member.ty, member.optional))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn struct_member_name(parent: &str, key: &str) -> String {
+    let mut chars = key.chars();
+    let capitalized = match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    };
+    format!("{parent}{capitalized}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_missing_from_either_sample_becomes_optional() {
+        let first = infer_value(&serde_json::json!({"a": 1, "b": 2}));
+        let second = infer_value(&serde_json::json!({"a": 3}));
+        let merged = merge(first, second);
+
+        let Inferred::Structure(members) = merged else {
+            panic!("expected a structure");
+        };
+        assert!(!members["a"].optional);
+        assert!(members["b"].optional);
+    }
+
+    #[test]
+    fn integer_widens_to_double_when_a_sibling_is_fractional() {
+        let merged = merge(Inferred::Long, Inferred::Double);
+        assert_eq!(merged, Inferred::Double);
+    }
+
+    #[test]
+    fn incompatible_types_fall_back_to_document() {
+        let merged = merge(Inferred::String, Inferred::Structure(BTreeMap::new()));
+        assert_eq!(merged, Inferred::Document);
+    }
+
+    #[test]
+    fn null_observation_does_not_panic_merge() {
+        let merged = merge(Inferred::Unresolved, Inferred::String);
+        assert_eq!(merged, Inferred::String);
+    }
+
+    #[test]
+    fn malformed_payload_is_an_error_not_a_panic() {
+        assert!(infer_schema(b"not json").is_err());
+    }
+}
@@ -0,0 +1,14 @@
+//! JSON codec for Smithy shapes: [`JsonSerializer`]/[`JsonDeserializer`]
+//! implement the core `Serializer`/`Deserializer` traits that a shape's
+//! `SerializeWithSchema`/`DeserializeWithSchema` impls drive, and [`infer`]
+//! lets callers bootstrap a [`Schema`](smithy4rs_core::schema::prelude::Schema)
+//! from a sample JSON payload instead of hand-writing one with the `smithy!`
+//! macro.
+
+mod de;
+pub mod infer;
+mod ser;
+
+pub use de::JsonDeserializer;
+pub use infer::infer_schema;
+pub use ser::JsonSerializer;
@@ -0,0 +1,205 @@
+//! JSON serialization, the write-side counterpart to `JsonDeserializer`.
+
+use smithy4rs_core::schema::prelude::Schema;
+use smithy4rs_core::serde::serializers::{
+    ListSerializer, SerializeError, SerializeWithSchema, Serializer, StructSerializer,
+};
+
+/// Serializes Smithy shapes to JSON by appending directly to a caller-owned
+/// buffer, mirroring `JsonDeserializer`'s borrow of the input buffer.
+pub struct JsonSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+}
+
+impl<'a> JsonSerializer<'a> {
+    pub fn new(buf: &'a mut Vec<u8>) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Serializer for JsonSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+    type SerializeStruct = JsonStructSerializer<'a>;
+    type SerializeList = JsonListSerializer<'a>;
+
+    fn serialize_struct(self, _schema: &Schema, _size: usize) -> Result<Self::SerializeStruct, Self::Error> {
+        self.buf.push(b'{');
+        Ok(JsonStructSerializer {
+            buf: self.buf,
+            wrote_any: false,
+        })
+    }
+
+    fn serialize_list(self, _schema: &Schema, _size: usize) -> Result<Self::SerializeList, Self::Error> {
+        self.buf.push(b'[');
+        Ok(JsonListSerializer {
+            buf: self.buf,
+            wrote_any: false,
+        })
+    }
+
+    fn serialize_boolean(self, value: bool) -> Result<Self::Ok, Self::Error> {
+        self.buf.extend_from_slice(if value { b"true" } else { b"false" });
+        Ok(())
+    }
+
+    fn serialize_integer(self, value: i32) -> Result<Self::Ok, Self::Error> {
+        self.buf.extend_from_slice(value.to_string().as_bytes());
+        Ok(())
+    }
+
+    fn serialize_long(self, value: i64) -> Result<Self::Ok, Self::Error> {
+        self.buf.extend_from_slice(value.to_string().as_bytes());
+        Ok(())
+    }
+
+    fn serialize_float(self, value: f32) -> Result<Self::Ok, Self::Error> {
+        self.buf.extend_from_slice(value.to_string().as_bytes());
+        Ok(())
+    }
+
+    fn serialize_double(self, value: f64) -> Result<Self::Ok, Self::Error> {
+        self.buf.extend_from_slice(value.to_string().as_bytes());
+        Ok(())
+    }
+
+    fn serialize_string(self, value: &str) -> Result<Self::Ok, Self::Error> {
+        write_json_string(self.buf, value);
+        Ok(())
+    }
+
+    fn serialize_blob(self, value: &[u8]) -> Result<Self::Ok, Self::Error> {
+        write_json_string(self.buf, &base64_encode(value));
+        Ok(())
+    }
+
+    fn serialize_null(self) -> Result<Self::Ok, Self::Error> {
+        self.buf.extend_from_slice(b"null");
+        Ok(())
+    }
+}
+
+/// Writes a structure's members as a JSON object, reusing the parent
+/// buffer for every nested member the way `CborStructSerializer` does.
+pub struct JsonStructSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+    wrote_any: bool,
+}
+
+impl<'a> StructSerializer for JsonStructSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_member<T: ?Sized + SerializeWithSchema>(
+        &mut self,
+        wire_name: &str,
+        member_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        if self.wrote_any {
+            self.buf.push(b',');
+        }
+        self.wrote_any = true;
+        write_json_string(self.buf, wire_name);
+        self.buf.push(b':');
+        value.serialize_with_schema(member_schema, JsonSerializer::new(&mut *self.buf))?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.buf.push(b'}');
+        Ok(())
+    }
+}
+
+/// Writes a list's elements as a JSON array.
+pub struct JsonListSerializer<'a> {
+    buf: &'a mut Vec<u8>,
+    wrote_any: bool,
+}
+
+impl<'a> ListSerializer for JsonListSerializer<'a> {
+    type Ok = ();
+    type Error = SerializeError;
+
+    fn serialize_element<T: ?Sized + SerializeWithSchema>(
+        &mut self,
+        element_schema: &Schema,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        if self.wrote_any {
+            self.buf.push(b',');
+        }
+        self.wrote_any = true;
+        value.serialize_with_schema(element_schema, JsonSerializer::new(&mut *self.buf))?;
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.buf.push(b']');
+        Ok(())
+    }
+}
+
+fn write_json_string(buf: &mut Vec<u8>, value: &str) {
+    buf.push(b'"');
+    for byte in value.bytes() {
+        match byte {
+            b'"' => buf.extend_from_slice(b"\\\""),
+            b'\\' => buf.extend_from_slice(b"\\\\"),
+            b'\n' => buf.extend_from_slice(b"\\n"),
+            b'\t' => buf.extend_from_slice(b"\\t"),
+            b'\r' => buf.extend_from_slice(b"\\r"),
+            0x08 => buf.extend_from_slice(b"\\b"),
+            0x0c => buf.extend_from_slice(b"\\f"),
+            _ => buf.push(byte),
+        }
+    }
+    buf.push(b'"');
+}
+
+/// Standard (non-URL-safe) base64 encoding, the write-side counterpart to
+/// `de::base64_decode`.
+fn base64_encode(input: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied().unwrap_or(0);
+        let b2 = chunk.get(2).copied().unwrap_or(0);
+        out.push(ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[(b2 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_trips_through_the_existing_decoder() {
+        for input in [&b""[..], b"f", b"fo", b"foo", b"foob", b"fooba", b"foobar"] {
+            let encoded = base64_encode(input);
+            assert_eq!(crate::de::base64_decode(&encoded).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn escapes_control_characters_and_quotes() {
+        let mut buf = Vec::new();
+        write_json_string(&mut buf, "a\"b\\c\n");
+        assert_eq!(buf, br#""a\"b\\c\n""#);
+    }
+}